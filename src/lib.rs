@@ -1,10 +1,18 @@
-//! This crate provides two structs, [`Advance`] and [`CountingAdvance`], to help with consuming iterators one step at
-//! a time. Refer to their respective documentation for more information.
+//! This crate provides four structs, [`Advance`], [`CountingAdvance`], [`DoubleEndedAdvance`] and [`LookaheadAdvance`],
+//! to help with consuming iterators one step at a time. Refer to their respective documentation for more information.
+
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
 
 /// Wrapper around an iterator. Has to be advanced using the
 /// [`advance`] method, which will cache the iterator's next element
 /// in `self.current`.
 ///
+/// Once the inner iterator yields `None`, `self` is considered exhausted and
+/// never polls it again, mirroring the fuse guarantee of the standard
+/// library's [`Fuse`](std::iter::Fuse) adapter. This matters for iterators
+/// that aren't well-behaved after returning `None`.
+///
 /// See also [`CountingAdvance`], a similar adapter that keeps track of how
 /// many times it has been advanced.
 ///
@@ -12,6 +20,7 @@
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Advance<I: Iterator> {
     current: Option<I::Item>,
+    exhausted: bool,
     iter: I,
 }
 
@@ -20,15 +29,71 @@ impl<I: Iterator> Advance<I> {
     /// `self.current` with the iterator's first element (if any).
     #[inline]
     pub fn new(mut iter: I) -> Self {
+        let current = iter.next();
+        let exhausted = current.is_none();
         Self {
-            current: iter.next(),
+            current,
+            exhausted,
             iter,
         }
     }
 
     #[inline]
     pub fn advance(&mut self) {
+        if self.exhausted {
+            self.current = None;
+            return;
+        }
         self.current = self.iter.next();
+        if self.current.is_none() {
+            self.exhausted = true;
+        }
+    }
+
+    /// Advances by `n` elements at once, mirroring the standard library's
+    /// `Iterator::advance_by` convention. The new `self.current` is the
+    /// `n`-th element from the previous one, i.e. advancing by `1` is
+    /// equivalent to calling [`advance`][Self::advance] once.
+    ///
+    /// This steps through the inner iterator one element at a time rather
+    /// than skipping via [`Iterator::nth`]: `nth` doesn't report how many
+    /// elements it consumed before returning `None`, and `size_hint`'s lower
+    /// bound isn't a trustworthy enough substitute (it's explicitly not
+    /// required to be accurate), so counting one-by-one is the only way to
+    /// report the exact shortfall below.
+    ///
+    /// If the iterator is exhausted after only `k < n` elements, `self.current`
+    /// is set to `None` and `Err` is returned holding the number of steps that
+    /// could not be taken (`n - k`). Once exhausted, this short-circuits without
+    /// polling the inner iterator again.
+    #[inline]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let mut taken = 0;
+        while taken < n {
+            if self.exhausted {
+                self.current = None;
+                return Err(NonZeroUsize::new(n - taken).unwrap());
+            }
+            match self.iter.next() {
+                Some(item) => {
+                    self.current = Some(item);
+                    taken += 1;
+                }
+                None => {
+                    self.current = None;
+                    self.exhausted = true;
+                    return Err(NonZeroUsize::new(n - taken).unwrap());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` once the inner iterator has yielded `None` and will
+    /// never be polled again.
+    #[inline]
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
     }
 
     #[inline]
@@ -40,12 +105,46 @@ impl<I: Iterator> Advance<I> {
     pub fn current_mut(&mut self) -> Option<&mut I::Item> {
         self.current.as_mut()
     }
+
+    /// Returns the bounds on the remaining length, i.e. the inner iterator's
+    /// own [`size_hint`][Iterator::size_hint] adjusted by one for the cached
+    /// `self.current`, if it is `Some`.
+    #[inline]
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.current.is_some() {
+            (lower + 1, upper.map(|upper| upper + 1))
+        } else {
+            (lower, upper)
+        }
+    }
+
+    /// Returns `true` if there is no cached current element, i.e. nothing is
+    /// left to advance through.
+    #[inline]
+    pub fn remaining_is_empty(&self) -> bool {
+        self.current.is_none()
+    }
+}
+
+impl<I: ExactSizeIterator> Advance<I> {
+    /// Returns the exact number of elements left, i.e. the inner iterator's
+    /// own [`len`][ExactSizeIterator::len] adjusted by one for the cached
+    /// `self.current`, if it is `Some`.
+    #[inline]
+    pub fn exact_remaining(&self) -> usize {
+        self.iter.len() + if self.current.is_some() { 1 } else { 0 }
+    }
 }
 
 /// Wrapper around an iterator. Has to be advanced using the
 /// [`advance`][adv_fn] method, which will cache the iterator's next element
 /// in `self.current` and increment `self.counter`.
 ///
+/// Once the inner iterator yields `None`, `self` is considered exhausted,
+/// never polls it again and stops incrementing `self.counter`, mirroring the
+/// fuse guarantee of the standard library's [`Fuse`](std::iter::Fuse) adapter.
+///
 /// See also [`Advance`], a similar adapter that does not keep track of how
 /// many times it has been advanced.
 ///
@@ -54,6 +153,7 @@ impl<I: Iterator> Advance<I> {
 pub struct CountingAdvance<I: Iterator> {
     counter: usize,
     current: Option<I::Item>,
+    exhausted: bool,
     iter: I,
 }
 
@@ -63,17 +163,75 @@ impl<I: Iterator> CountingAdvance<I> {
     /// and starts the counter at zero.
     #[inline]
     pub fn new(mut iter: I) -> Self {
+        let current = iter.next();
+        let exhausted = current.is_none();
         Self {
             counter: 0,
-            current: iter.next(),
+            current,
+            exhausted,
             iter,
         }
     }
 
     #[inline]
     pub fn advance(&mut self) {
+        if self.exhausted {
+            self.current = None;
+            return;
+        }
         self.counter += 1;
         self.current = self.iter.next();
+        if self.current.is_none() {
+            self.exhausted = true;
+        }
+    }
+
+    /// Advances by `n` elements at once, mirroring the standard library's
+    /// `Iterator::advance_by` convention. `self.counter` is incremented once
+    /// per poll of the inner iterator, as [`advance`][Self::advance] does, not
+    /// by `n`: if the iterator runs dry after `k < n` elements, it grows by
+    /// `k + 1`, the final poll that found it exhausted included.
+    ///
+    /// This steps through the inner iterator one element at a time rather
+    /// than skipping via [`Iterator::nth`]: `nth` doesn't report how many
+    /// elements it consumed before returning `None`, and `size_hint`'s lower
+    /// bound isn't a trustworthy enough substitute (it's explicitly not
+    /// required to be accurate), so counting one-by-one is the only way to
+    /// report the exact shortfall below.
+    ///
+    /// If the iterator is exhausted after only `k < n` elements, `self.current`
+    /// is set to `None` and `Err` is returned holding the number of steps that
+    /// could not be taken (`n - k`). Once exhausted, this short-circuits without
+    /// polling the inner iterator or incrementing `self.counter` again.
+    #[inline]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let mut taken = 0;
+        while taken < n {
+            if self.exhausted {
+                self.current = None;
+                return Err(NonZeroUsize::new(n - taken).unwrap());
+            }
+            self.counter += 1;
+            match self.iter.next() {
+                Some(item) => {
+                    self.current = Some(item);
+                    taken += 1;
+                }
+                None => {
+                    self.current = None;
+                    self.exhausted = true;
+                    return Err(NonZeroUsize::new(n - taken).unwrap());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` once the inner iterator has yielded `None` and will
+    /// never be polled again.
+    #[inline]
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
     }
 
     #[inline]
@@ -90,4 +248,527 @@ impl<I: Iterator> CountingAdvance<I> {
     pub fn current_mut(&mut self) -> Option<&mut I::Item> {
         self.current.as_mut()
     }
+
+    /// Returns the bounds on the remaining length, i.e. the inner iterator's
+    /// own [`size_hint`][Iterator::size_hint] adjusted by one for the cached
+    /// `self.current`, if it is `Some`.
+    #[inline]
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.current.is_some() {
+            (lower + 1, upper.map(|upper| upper + 1))
+        } else {
+            (lower, upper)
+        }
+    }
+
+    /// Returns `true` if there is no cached current element, i.e. nothing is
+    /// left to advance through.
+    #[inline]
+    pub fn remaining_is_empty(&self) -> bool {
+        self.current.is_none()
+    }
+}
+
+impl<I: ExactSizeIterator> CountingAdvance<I> {
+    /// Returns the exact number of elements left, i.e. the inner iterator's
+    /// own [`len`][ExactSizeIterator::len] adjusted by one for the cached
+    /// `self.current`, if it is `Some`. Combine this with [`counter`][Self::counter]
+    /// to compute a total length estimate (`counter() + exact_remaining()`) while
+    /// driving the cursor manually.
+    #[inline]
+    pub fn exact_remaining(&self) -> usize {
+        self.iter.len() + if self.current.is_some() { 1 } else { 0 }
+    }
+}
+
+/// Wrapper around a [`DoubleEndedIterator`]. Caches an element at both ends
+/// simultaneously, advanced independently using [`advance_front`] and
+/// [`advance_back`]. This lets callers walk a sequence inward from both ends
+/// at once (parser lookahead, palindrome-style scans) without collecting
+/// into a `VecDeque`.
+///
+/// The two cached elements never alias: once the inner iterator reports that
+/// front and back have met (either side's pull returns `None`), the adapter
+/// is considered converged and stops polling the inner iterator entirely, so
+/// a spent side is never resurrected as a duplicate of the other.
+///
+/// [`advance_front`]: DoubleEndedAdvance::advance_front
+/// [`advance_back`]: DoubleEndedAdvance::advance_back
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DoubleEndedAdvance<I: DoubleEndedIterator> {
+    front_current: Option<I::Item>,
+    back_current: Option<I::Item>,
+    converged: bool,
+    iter: I,
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedAdvance<I> {
+    /// Wraps the given iterator in a [`DoubleEndedAdvance`] adapter. This
+    /// initiates `self.front_current` with the iterator's first element and
+    /// `self.back_current` with its last element (if any), without querying
+    /// the back end at all when the front end was already empty.
+    #[inline]
+    pub fn new(mut iter: I) -> Self {
+        let front_current = iter.next();
+        if front_current.is_none() {
+            return Self {
+                front_current: None,
+                back_current: None,
+                converged: true,
+                iter,
+            };
+        }
+        let back_current = iter.next_back();
+        let converged = back_current.is_none();
+        Self {
+            front_current,
+            back_current,
+            converged,
+            iter,
+        }
+    }
+
+    #[inline]
+    pub fn advance_front(&mut self) {
+        if self.converged {
+            self.front_current = None;
+            return;
+        }
+        match self.iter.next() {
+            Some(item) => self.front_current = Some(item),
+            None => {
+                self.front_current = None;
+                self.converged = true;
+            }
+        }
+    }
+
+    #[inline]
+    pub fn advance_back(&mut self) {
+        if self.converged {
+            self.back_current = None;
+            return;
+        }
+        match self.iter.next_back() {
+            Some(item) => self.back_current = Some(item),
+            None => {
+                self.back_current = None;
+                self.converged = true;
+            }
+        }
+    }
+
+    /// Advances the back end by `n` elements at once, mirroring
+    /// [`advance_back`][Self::advance_back] the way [`Advance::advance_by`]
+    /// mirrors [`Advance::advance`] — including stepping through `next_back`
+    /// one element at a time rather than skipping via `nth_back`, for the
+    /// same reason `advance_by` doesn't use `nth`: there's no trustworthy way
+    /// to know in advance that `n` elements remain, and the exact shortfall
+    /// below can only be derived by counting.
+    ///
+    /// If the iterator converges after only `k < n` elements, `self.back_current`
+    /// is set to `None` and `Err` is returned holding the number of steps that
+    /// could not be taken (`n - k`).
+    #[inline]
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let mut taken = 0;
+        while taken < n {
+            if self.converged {
+                self.back_current = None;
+                return Err(NonZeroUsize::new(n - taken).unwrap());
+            }
+            match self.iter.next_back() {
+                Some(item) => {
+                    self.back_current = Some(item);
+                    taken += 1;
+                }
+                None => {
+                    self.back_current = None;
+                    self.converged = true;
+                    return Err(NonZeroUsize::new(n - taken).unwrap());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn front(&self) -> Option<&I::Item> {
+        self.front_current.as_ref()
+    }
+
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut I::Item> {
+        self.front_current.as_mut()
+    }
+
+    #[inline]
+    pub fn back(&self) -> Option<&I::Item> {
+        self.back_current.as_ref()
+    }
+
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut I::Item> {
+        self.back_current.as_mut()
+    }
+}
+
+/// Wrapper around an iterator that caches up to `N` upcoming elements instead
+/// of just one, so callers can [`peek`] more than one element ahead while
+/// still advancing one step at a time. `peek(0)` is the current element, the
+/// counterpart of [`Advance::current`].
+///
+/// The buffer holds `min(N, remaining)` items; peeking past the end of the
+/// buffer, whether because the window hasn't filled up or because the inner
+/// iterator is exhausted, returns `None`. This adapter is fused like
+/// [`Advance`]: once the inner iterator yields `None`, it is never polled
+/// again.
+///
+/// With `N == 0` the window never caches anything, so [`new`] doesn't probe
+/// the inner iterator at all (doing so would mean dropping its first element
+/// with no buffer to hold it). [`advance`] still polls the inner iterator
+/// once per call in that case, purely to keep [`is_exhausted`] accurate.
+///
+/// [`peek`]: LookaheadAdvance::peek
+/// [`new`]: LookaheadAdvance::new
+/// [`advance`]: LookaheadAdvance::advance
+/// [`is_exhausted`]: LookaheadAdvance::is_exhausted
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LookaheadAdvance<I: Iterator, const N: usize> {
+    buffer: VecDeque<I::Item>,
+    exhausted: bool,
+    iter: I,
+}
+
+impl<I: Iterator, const N: usize> LookaheadAdvance<I, N> {
+    /// Wraps the given iterator in a [`LookaheadAdvance`] adapter. This fills
+    /// the lookahead buffer with up to `N` elements right away.
+    #[inline]
+    pub fn new(mut iter: I) -> Self {
+        let mut buffer = VecDeque::with_capacity(N);
+        let mut exhausted = false;
+        for _ in 0..N {
+            match iter.next() {
+                Some(item) => buffer.push_back(item),
+                None => {
+                    exhausted = true;
+                    break;
+                }
+            }
+        }
+        Self {
+            buffer,
+            exhausted,
+            iter,
+        }
+    }
+
+    /// Returns the element `k` positions ahead of the current one (`k == 0`
+    /// is the current element), or `None` if fewer than `k + 1` elements
+    /// remain.
+    #[inline]
+    pub fn peek(&self, k: usize) -> Option<&I::Item> {
+        self.buffer.get(k)
+    }
+
+    /// Pops the front of the buffer and, unless already exhausted, refills
+    /// the tail from the inner iterator. When `N == 0` there is no buffer to
+    /// refill, but the inner iterator is still polled once so exhaustion is
+    /// detected instead of going unnoticed forever.
+    #[inline]
+    pub fn advance(&mut self) {
+        self.buffer.pop_front();
+        if self.exhausted {
+            return;
+        }
+        if self.buffer.len() < N {
+            match self.iter.next() {
+                Some(item) => self.buffer.push_back(item),
+                None => self.exhausted = true,
+            }
+        } else if N == 0 && self.iter.next().is_none() {
+            self.exhausted = true;
+        }
+    }
+
+    /// Returns `true` once the inner iterator has yielded `None` and will
+    /// never be polled again.
+    #[inline]
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_by_advances_multiple_elements() {
+        let mut advance = Advance::new(0..10);
+        assert_eq!(advance.advance_by(9), Ok(()));
+        assert_eq!(advance.current(), Some(&9));
+        assert_eq!(advance.advance_by(1), Err(NonZeroUsize::new(1).unwrap()));
+        assert_eq!(advance.current(), None);
+    }
+
+    /// An iterator whose `size_hint` lower bound lies about how many
+    /// elements are actually left, to guard against trusting it as a proxy
+    /// for "at least `n` elements remain".
+    struct LyingSizeHint {
+        remaining: usize,
+    }
+
+    impl Iterator for LyingSizeHint {
+        type Item = usize;
+
+        fn next(&mut self) -> Option<usize> {
+            if self.remaining == 0 {
+                return None;
+            }
+            self.remaining -= 1;
+            Some(self.remaining)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.remaining + 100, None)
+        }
+    }
+
+    #[test]
+    fn advance_by_does_not_trust_an_inflated_size_hint() {
+        // `remaining: 2` yields 2 elements total; `new` consumes the first as
+        // `current`, leaving exactly 1 more for `advance_by` to take before
+        // the iterator runs dry.
+        let mut advance = Advance::new(LyingSizeHint { remaining: 2 });
+        let err = advance.advance_by(5).unwrap_err();
+        assert_eq!(err, NonZeroUsize::new(4).unwrap());
+        assert_eq!(advance.current(), None);
+        assert!(advance.is_exhausted());
+    }
+
+    #[test]
+    fn counting_advance_by_does_not_trust_an_inflated_size_hint() {
+        let mut advance = CountingAdvance::new(LyingSizeHint { remaining: 2 });
+        let err = advance.advance_by(5).unwrap_err();
+        assert_eq!(err, NonZeroUsize::new(4).unwrap());
+        assert_eq!(advance.counter(), 2);
+        assert!(advance.is_exhausted());
+    }
+
+    #[test]
+    fn advance_by_zero_is_a_no_op() {
+        let mut advance = Advance::new(0..3);
+        assert_eq!(advance.advance_by(0), Ok(()));
+        assert_eq!(advance.current(), Some(&0));
+    }
+
+    #[test]
+    fn advance_by_reports_exact_shortfall_when_exhausted() {
+        // `0..3` has 1 element cached as `current` plus 2 left in `iter`, so
+        // only 2 of the requested 10 steps can be taken.
+        let mut advance = Advance::new(0..3);
+        let err = advance.advance_by(10).unwrap_err();
+        assert_eq!(err, NonZeroUsize::new(8).unwrap());
+        assert_eq!(advance.current(), None);
+        assert!(advance.is_exhausted());
+
+        let err = advance.advance_by(5).unwrap_err();
+        assert_eq!(err, NonZeroUsize::new(5).unwrap());
+    }
+
+    #[test]
+    fn counting_advance_by_increments_counter_by_steps_taken_not_n() {
+        let mut advance = CountingAdvance::new(0..3);
+        let err = advance.advance_by(10).unwrap_err();
+        assert_eq!(err, NonZeroUsize::new(8).unwrap());
+        assert_eq!(advance.counter(), 3);
+        assert!(advance.is_exhausted());
+
+        advance.advance_by(3).unwrap_err();
+        assert_eq!(advance.counter(), 3);
+    }
+
+    #[test]
+    fn counting_advance_by_advances_multiple_elements() {
+        let mut advance = CountingAdvance::new(0..10);
+        assert_eq!(advance.advance_by(5), Ok(()));
+        assert_eq!(advance.counter(), 5);
+        assert_eq!(advance.current(), Some(&5));
+    }
+
+    #[test]
+    fn double_ended_advance_walks_inward_from_both_ends() {
+        let mut advance = DoubleEndedAdvance::new(0..5);
+        assert_eq!(advance.front(), Some(&0));
+        assert_eq!(advance.back(), Some(&4));
+
+        advance.advance_front();
+        assert_eq!(advance.front(), Some(&1));
+        advance.advance_back();
+        assert_eq!(advance.back(), Some(&3));
+    }
+
+    #[test]
+    fn double_ended_advance_converges_without_aliasing() {
+        // `0..2` has exactly two elements, so front and back start out equal
+        // to them; advancing either side must not resurrect the other's
+        // element once the inner iterator is spent.
+        let mut advance = DoubleEndedAdvance::new(0..2);
+        assert_eq!(advance.front(), Some(&0));
+        assert_eq!(advance.back(), Some(&1));
+
+        advance.advance_front();
+        assert_eq!(advance.front(), None);
+        assert_eq!(advance.back(), Some(&1));
+
+        advance.advance_back();
+        assert_eq!(advance.back(), None);
+    }
+
+    #[test]
+    fn double_ended_advance_handles_empty_iterator() {
+        let mut advance = DoubleEndedAdvance::new(0..0);
+        assert_eq!(advance.front(), None);
+        assert_eq!(advance.back(), None);
+
+        advance.advance_front();
+        advance.advance_back();
+        assert_eq!(advance.front(), None);
+        assert_eq!(advance.back(), None);
+    }
+
+    #[test]
+    fn double_ended_advance_back_by_reports_exact_shortfall() {
+        let mut advance = DoubleEndedAdvance::new(0..3);
+        let err = advance.advance_back_by(5).unwrap_err();
+        assert_eq!(err, NonZeroUsize::new(4).unwrap());
+        assert_eq!(advance.back(), None);
+    }
+
+    #[test]
+    fn size_hint_and_remaining_include_cached_current() {
+        let mut advance = Advance::new(0..3);
+        assert_eq!(advance.size_hint(), (3, Some(3)));
+        assert_eq!(advance.exact_remaining(), 3);
+        assert!(!advance.remaining_is_empty());
+
+        advance.advance_by(3).unwrap_err();
+        assert_eq!(advance.size_hint(), (0, Some(0)));
+        assert_eq!(advance.exact_remaining(), 0);
+        assert!(advance.remaining_is_empty());
+    }
+
+    #[test]
+    fn counting_advance_counter_plus_remaining_is_total_length() {
+        let mut advance = CountingAdvance::new(0..5);
+        advance.advance();
+        advance.advance();
+        assert_eq!(advance.counter() + advance.exact_remaining(), 5);
+    }
+
+    /// An iterator that panics if it is polled again after it has already
+    /// returned `None` once, to prove a consumer never re-polls a spent
+    /// iterator.
+    struct PanicsIfPolledAfterNone {
+        remaining: usize,
+        spent: bool,
+    }
+
+    impl Iterator for PanicsIfPolledAfterNone {
+        type Item = usize;
+
+        fn next(&mut self) -> Option<usize> {
+            assert!(!self.spent, "polled again after already returning None");
+            if self.remaining == 0 {
+                self.spent = true;
+                return None;
+            }
+            self.remaining -= 1;
+            Some(self.remaining)
+        }
+    }
+
+    #[test]
+    fn advance_never_repolls_after_exhaustion() {
+        let mut advance = Advance::new(PanicsIfPolledAfterNone {
+            remaining: 2,
+            spent: false,
+        });
+        assert!(!advance.is_exhausted());
+        advance.advance();
+        advance.advance();
+        assert!(advance.is_exhausted());
+        // These would panic if `advance` polled the inner iterator again.
+        advance.advance();
+        advance.advance();
+        assert_eq!(advance.current(), None);
+    }
+
+    #[test]
+    fn counting_advance_stops_incrementing_counter_after_exhaustion() {
+        let mut advance = CountingAdvance::new(PanicsIfPolledAfterNone {
+            remaining: 1,
+            spent: false,
+        });
+        advance.advance();
+        advance.advance();
+        assert!(advance.is_exhausted());
+        let counter_at_exhaustion = advance.counter();
+        advance.advance();
+        advance.advance();
+        assert_eq!(advance.counter(), counter_at_exhaustion);
+    }
+
+    #[test]
+    fn lookahead_advance_peeks_the_window_and_shifts_on_advance() {
+        let mut lookahead = LookaheadAdvance::<_, 3>::new(0..5);
+        assert_eq!(lookahead.peek(0), Some(&0));
+        assert_eq!(lookahead.peek(2), Some(&2));
+        assert_eq!(lookahead.peek(3), None);
+
+        lookahead.advance();
+        assert_eq!(lookahead.peek(0), Some(&1));
+        assert_eq!(lookahead.peek(2), Some(&3));
+    }
+
+    #[test]
+    fn lookahead_advance_shrinks_the_window_as_it_runs_dry() {
+        // Fewer than `N` elements exist, so the window never fully fills and
+        // `new` already detects exhaustion without any `advance` call.
+        let mut lookahead = LookaheadAdvance::<_, 3>::new(0..2);
+        assert_eq!(lookahead.peek(0), Some(&0));
+        assert_eq!(lookahead.peek(1), Some(&1));
+        assert_eq!(lookahead.peek(2), None);
+        assert!(lookahead.is_exhausted());
+
+        lookahead.advance();
+        assert_eq!(lookahead.peek(0), Some(&1));
+
+        lookahead.advance();
+        assert_eq!(lookahead.peek(0), None);
+    }
+
+    #[test]
+    fn lookahead_advance_with_zero_width_window_still_tracks_exhaustion() {
+        let mut lookahead = LookaheadAdvance::<_, 0>::new(0..0);
+        assert_eq!(lookahead.peek(0), None);
+        assert!(!lookahead.is_exhausted());
+
+        lookahead.advance();
+        assert!(lookahead.is_exhausted());
+
+        // Further advances must not re-poll the now-spent inner iterator.
+        let mut lookahead = LookaheadAdvance::<_, 0>::new(PanicsIfPolledAfterNone {
+            remaining: 1,
+            spent: false,
+        });
+        lookahead.advance();
+        assert!(!lookahead.is_exhausted());
+        lookahead.advance();
+        assert!(lookahead.is_exhausted());
+        lookahead.advance();
+        assert!(lookahead.is_exhausted());
+    }
 }